@@ -1,13 +1,32 @@
 use regex::Regex;
 use std::io::{Result, Write};
+use std::sync::OnceLock;
 use textwrap::fill;
-use unicode_width::UnicodeWidthStr;
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
 
-const MASCOT: &[u8] = if cfg!(feature = "clippy") {
-    br#"
-        \
-         \
-            __
+/// Which mascot speaks, selectable at runtime via [`SayBuilder::mascot`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Mascot {
+    Ferris,
+    Clippy,
+}
+
+fn default_mascot() -> Mascot {
+    if cfg!(feature = "clippy") {
+        Mascot::Clippy
+    } else {
+        Mascot::Ferris
+    }
+}
+
+const FERRIS_BODY: &[u8] = br#"            _~^~^~_
+        \) /  o o  \ (/
+          '_   -   _'
+          / '-----' \
+"#;
+
+const CLIPPY_BODY: &[u8] = br#"            __
            /  \
            |  |
            @  @
@@ -16,16 +35,43 @@ const MASCOT: &[u8] = if cfg!(feature = "clippy") {
            || ||
            |\_/|
            \___/
-"#
-} else {
-    br#"
-        \
-         \
-            _~^~^~_
-        \) /  o o  \ (/
-          '_   -   _'
-          / '-----' \
-"#
+"#;
+
+// The lines that tether the speech/think bubble to the mascot's body.
+const SPEECH_TETHER: &[u8] = b"\n        \\\n         \\\n";
+const THINK_TETHER: &[u8] = b"\n        o\n         o\n          o\n";
+
+fn mascot_bytes(mascot: Mascot, think: bool) -> Vec<u8> {
+    let tether: &[u8] = if think { THINK_TETHER } else { SPEECH_TETHER };
+    let body: &[u8] = match mascot {
+        Mascot::Ferris => FERRIS_BODY,
+        Mascot::Clippy => CLIPPY_BODY,
+    };
+    [tether, body].concat()
+}
+
+/// The border characters drawn around each bubble row. `single` is used
+/// when there is only one line, and `first`/`middle`/`last` are used for
+/// the first, interior, and final lines of a multi-line bubble.
+struct Delimiters {
+    single: (&'static [u8], &'static [u8]),
+    first: (&'static [u8], &'static [u8]),
+    middle: (&'static [u8], &'static [u8]),
+    last: (&'static [u8], &'static [u8]),
+}
+
+const SPEECH_DELIMITERS: Delimiters = Delimiters {
+    single: (b"< ", b" >"),
+    first: (b"/ ", b" \\"),
+    middle: (b"| ", b" |"),
+    last: (b"\\ ", b" /"),
+};
+
+const THINK_DELIMITERS: Delimiters = Delimiters {
+    single: (b"( ", b" )"),
+    first: (b"( ", b" )"),
+    middle: (b"( ", b" )"),
+    last: (b"( ", b" )"),
 };
 
 /// Print out Ferris saying something.
@@ -66,24 +112,288 @@ const MASCOT: &[u8] = if cfg!(feature = "clippy") {
 ///           '_   -   _'
 ///           / '-----' \
 /// ```
-pub fn say<W>(input: &str, max_width: usize, mut writer: W) -> Result<()>
+pub fn say<W>(input: &str, max_width: usize, writer: W) -> Result<()>
 where
     W: Write,
 {
-    // Final output is stored here
-    let mut write_buffer = Vec::new();
+    SayBuilder::new().say(input, max_width, writer)
+}
 
-    // Pre process to merge continuous whitespaces into one space character
-    let input = merge_white_spaces(input);
+/// Print out Ferris saying something, preserving the input's existing line
+/// breaks and leading indentation instead of collapsing all whitespace.
+///
+/// Lines that already fit `max_width` are left untouched, so intentional
+/// formatting like code snippets, ASCII tables, or aligned lists survives.
+/// Only lines that exceed `max_width` are wrapped, and a wrapped line's
+/// continuation is indented to match the original line's leading
+/// whitespace. The bubble still sizes to the widest resulting line.
+///
+/// # Example
+///
+/// ```rust
+/// use ferris_says::say_verbatim;
+/// use std::io::{stdout, BufWriter};
+///
+/// let stdout = stdout();
+/// let out = "fn main() {\n    println!(\"hi\");\n}";
+/// let writer = BufWriter::new(stdout.lock());
+/// say_verbatim(out, 24, writer).unwrap();
+/// ```
+pub fn say_verbatim<W>(input: &str, max_width: usize, writer: W) -> Result<()>
+where
+    W: Write,
+{
+    SayBuilder::new().verbatim(true).say(input, max_width, writer)
+}
 
-    // Let textwrap work its magic
-    let wrapped = fill(input.as_str(), max_width);
+/// Print out Ferris saying something, using a custom line ending instead of
+/// a bare `\n`.
+///
+/// This is useful when the output is going somewhere that expects CRLF line
+/// endings, such as a Windows console or a protocol that mandates them.
+/// `ending` is applied uniformly to the top border, every bubble row, the
+/// bottom border, and the mascot.
+///
+/// # Example
+///
+/// ```rust
+/// use ferris_says::say_with_ending;
+/// use std::io::{stdout, BufWriter};
+///
+/// let stdout = stdout();
+/// let out = "Hello fellow Rustaceans!";
+/// let writer = BufWriter::new(stdout.lock());
+/// say_with_ending(out, 24, b"\r\n", writer).unwrap();
+/// ```
+pub fn say_with_ending<W>(input: &str, max_width: usize, ending: &[u8], writer: W) -> Result<()>
+where
+    W: Write,
+{
+    SayBuilder::new()
+        .line_ending(ending)
+        .say(input, max_width, writer)
+}
+
+/// Print out Ferris saying something, bounding both the height and width of
+/// the bubble.
+///
+/// `input` is a string slice that you want to be written out to somewhere
+///
+/// `max_width` is the maximum width of a line of text before it is wrapped
+///
+/// `max_lines` is the maximum number of lines the bubble may grow to; once
+/// wrapping would produce more lines than this, the overflow is collapsed
+/// into a single final line truncated with an ellipsis (`…`). A single line
+/// that is too wide to fit `max_width` (for example an unbreakable word) is
+/// likewise truncated with a trailing ellipsis rather than left overhanging.
+///
+/// `writer` is anywhere that can be written to using the Writer trait like
+/// STDOUT or STDERR
+///
+/// # Example
+///
+/// ```rust
+/// use ferris_says::say_truncated;
+/// use std::io::{stdout, BufWriter};
+///
+/// let stdout = stdout();
+/// let out = "Hello fellow Rustaceans! ".repeat(20);
+/// let writer = BufWriter::new(stdout.lock());
+/// say_truncated(&out, 24, 4, writer).unwrap();
+/// ```
+pub fn say_truncated<W>(input: &str, max_width: usize, max_lines: usize, writer: W) -> Result<()>
+where
+    W: Write,
+{
+    SayBuilder::new()
+        .max_lines(max_lines)
+        .say(input, max_width, writer)
+}
 
-    let lines: Vec<&str> = wrapped.lines().collect();
+/// How input text is turned into the lines that go inside the bubble.
+enum WrapMode {
+    /// Collapse interior whitespace, then word-wrap the whole input to
+    /// `max_width` via `textwrap::fill`. This is the original, default
+    /// behavior.
+    Wrap,
+    /// Preserve existing line breaks and leading indentation, only wrapping
+    /// lines that individually exceed `max_width`.
+    Verbatim,
+}
+
+/// Builds a configured speaker: which mascot it is, whether it's thinking or
+/// speaking, what line ending its output uses, and how overlong input is
+/// handled. This is the general entry point for embedding ferris-says (or
+/// one of its mascots) into a tool that wants more control than the
+/// `say`/`say_truncated`/`say_verbatim`/`say_with_ending` free functions
+/// give you; those are thin wrappers around a default-configured builder.
+///
+/// # Example
+///
+/// ```rust
+/// use ferris_says::{Mascot, SayBuilder};
+/// use std::io::{stdout, BufWriter};
+///
+/// let stdout = stdout();
+/// let writer = BufWriter::new(stdout.lock());
+/// SayBuilder::new()
+///     .mascot(Mascot::Clippy)
+///     .think(true)
+///     .say("What is the best lint?", 24, writer)
+///     .unwrap();
+/// ```
+pub struct SayBuilder {
+    mascot: Mascot,
+    think: bool,
+    ending: Vec<u8>,
+    verbatim: bool,
+    max_lines: Option<usize>,
+}
+
+impl Default for SayBuilder {
+    fn default() -> Self {
+        SayBuilder {
+            mascot: default_mascot(),
+            think: false,
+            ending: b"\n".to_vec(),
+            verbatim: false,
+            max_lines: None,
+        }
+    }
+}
+
+impl SayBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Choose which mascot speaks. Defaults to `Mascot::Ferris`, or
+    /// `Mascot::Clippy` when built with the `clippy` feature.
+    pub fn mascot(mut self, mascot: Mascot) -> Self {
+        self.mascot = mascot;
+        self
+    }
+
+    /// Render a thought bubble (dotted tether, rounded `( )` delimiters)
+    /// instead of a speech bubble (`< >`/`/ \` delimiters). Defaults to
+    /// `false`.
+    pub fn think(mut self, think: bool) -> Self {
+        self.think = think;
+        self
+    }
+
+    /// Set the line ending written after the top border, each bubble row,
+    /// the bottom border, and the mascot. Defaults to `b"\n"`.
+    pub fn line_ending(mut self, ending: impl Into<Vec<u8>>) -> Self {
+        self.ending = ending.into();
+        self
+    }
+
+    /// Preserve the input's existing line breaks and indentation instead of
+    /// collapsing whitespace and word-wrapping it. Defaults to `false`. See
+    /// [`say_verbatim`].
+    pub fn verbatim(mut self, verbatim: bool) -> Self {
+        self.verbatim = verbatim;
+        self
+    }
+
+    /// Bound the bubble to at most this many lines, eliding overflow with
+    /// an ellipsis. Defaults to unbounded. See [`say_truncated`].
+    pub fn max_lines(mut self, max_lines: usize) -> Self {
+        self.max_lines = Some(max_lines);
+        self
+    }
+
+    /// Render `input` with this configuration, writing the result to
+    /// `writer`.
+    pub fn say<W>(&self, input: &str, max_width: usize, writer: W) -> Result<()>
+    where
+        W: Write,
+    {
+        let mode = if self.verbatim {
+            WrapMode::Verbatim
+        } else {
+            WrapMode::Wrap
+        };
+        let delimiters = if self.think {
+            &THINK_DELIMITERS
+        } else {
+            &SPEECH_DELIMITERS
+        };
+        let mascot = mascot_bytes(self.mascot, self.think);
+        let render = RenderConfig {
+            ending: &self.ending,
+            delimiters,
+            mascot: &mascot,
+        };
+        say_impl(input, max_width, mode, self.max_lines, &render, writer)
+    }
+}
+
+/// Everything needed to turn wrapped lines into bytes, bundled together so
+/// `say_impl` doesn't have to take each piece as its own argument.
+struct RenderConfig<'a> {
+    ending: &'a [u8],
+    delimiters: &'a Delimiters,
+    mascot: &'a [u8],
+}
+
+fn say_impl<W>(
+    input: &str,
+    max_width: usize,
+    mode: WrapMode,
+    max_lines: Option<usize>,
+    render: &RenderConfig<'_>,
+    writer: W,
+) -> Result<()>
+where
+    W: Write,
+{
+    let mut lines: Vec<String> = match mode {
+        WrapMode::Wrap => {
+            // Pre process to merge continuous whitespaces into one space character
+            let input = merge_white_spaces(input);
+            // Let textwrap work its magic
+            fill(input.as_str(), max_width)
+                .lines()
+                .map(str::to_owned)
+                .collect()
+        }
+        WrapMode::Verbatim => format_verbatim(input, max_width),
+    };
+
+    if let Some(max_lines) = max_lines {
+        // A single unbreakable token can still overhang max_width; truncate
+        // any such line in place before deciding whether we also need to
+        // collapse the overall line count.
+        for line in lines.iter_mut() {
+            if line_width(line) > max_width {
+                *line = elide_to_width(line, max_width);
+            }
+        }
+
+        if lines.len() > max_lines {
+            let kept = max_lines.saturating_sub(1);
+            let overflow = lines.split_off(kept).join(" ");
+            lines.truncate(kept);
+            lines.push(elide_to_width(&overflow, max_width));
+        }
+    }
+
+    let lines: Vec<&str> = lines.iter().map(String::as_str).collect();
 
     let line_count = lines.len();
     let actual_width = longest_line(&lines);
 
+    // Final output is stored here. Pre-sized from the box's dimensions:
+    // `actual_width + 4` bytes per row (border and padding), for
+    // `line_count + 2` rows counting the top/bottom borders, plus the
+    // mascot. This is an estimate, not a bound: each bubble row also
+    // carries a `\n`, and multi-byte characters take more bytes than
+    // columns, so the buffer can still grow for multi-line bubbles.
+    let mut write_buffer =
+        Vec::with_capacity((actual_width + 4) * (line_count + 2) + render.mascot.len());
+
     // top box border
     write_buffer.push(b' ');
     for _ in 0..(actual_width + 2) {
@@ -93,31 +403,26 @@ where
 
     // inner message
     for (i, line) in lines.into_iter().enumerate() {
-        if line_count == 1 {
-            write_buffer.extend_from_slice(b"< ");
+        let (prefix, suffix) = if line_count == 1 {
+            render.delimiters.single
         } else if i == 0 {
-            write_buffer.extend_from_slice(b"/ ");
+            render.delimiters.first
         } else if i == line_count - 1 {
-            write_buffer.extend_from_slice(b"\\ ");
+            render.delimiters.last
         } else {
-            write_buffer.extend_from_slice(b"| ");
-        }
+            render.delimiters.middle
+        };
 
-        let line_len = UnicodeWidthStr::width(line);
+        write_buffer.extend_from_slice(prefix);
+
+        let line_len = line_width(line);
         write_buffer.extend_from_slice(line.as_bytes());
         for _ in line_len..actual_width {
             write_buffer.push(b' ');
         }
 
-        if line_count == 1 {
-            write_buffer.extend_from_slice(b" >\n");
-        } else if i == 0 {
-            write_buffer.extend_from_slice(b" \\\n");
-        } else if i == line_count - 1 {
-            write_buffer.extend_from_slice(b" /\n");
-        } else {
-            write_buffer.extend_from_slice(b" |\n");
-        }
+        write_buffer.extend_from_slice(suffix);
+        write_buffer.push(b'\n');
     }
 
     // bottom box border
@@ -127,21 +432,122 @@ where
     }
 
     // mascot
-    write_buffer.extend_from_slice(MASCOT);
+    write_buffer.extend_from_slice(render.mascot);
+
+    LineEndingWriter::with_ending(writer, render.ending).write_all(&write_buffer)
+}
+
+/// Wraps a [`Write`] and rewrites every `\n` passed through it into a
+/// caller-chosen line ending, so code that builds its output with plain
+/// `\n`s doesn't need to know what the final ending should be.
+struct LineEndingWriter<'a, W> {
+    inner: W,
+    ending: &'a [u8],
+}
+
+impl<'a, W: Write> LineEndingWriter<'a, W> {
+    fn with_ending(inner: W, ending: &'a [u8]) -> Self {
+        LineEndingWriter { inner, ending }
+    }
+}
+
+impl<W: Write> Write for LineEndingWriter<'_, W> {
+    fn write(&mut self, buf: &[u8]) -> Result<usize> {
+        let mut start = 0;
+        for (i, &byte) in buf.iter().enumerate() {
+            if byte == b'\n' {
+                self.inner.write_all(&buf[start..i])?;
+                self.inner.write_all(self.ending)?;
+                start = i + 1;
+            }
+        }
+        self.inner.write_all(&buf[start..])?;
+        Ok(buf.len())
+    }
 
-    writer.write_all(&write_buffer)
+    fn flush(&mut self) -> Result<()> {
+        self.inner.flush()
+    }
 }
 
 fn longest_line(lines: &[&str]) -> usize {
-    lines
-        .iter()
-        .map(|line| UnicodeWidthStr::width(*line))
-        .max()
-        .unwrap_or(0)
+    lines.iter().map(|line| line_width(line)).max().unwrap_or(0)
+}
+
+/// The display width of `s` in terminal columns, measured cluster-by-cluster
+/// rather than codepoint-by-codepoint. Grapheme segmentation keeps a base
+/// character plus its combining marks, or a multi-codepoint ZWJ emoji
+/// sequence, counting as one cell whose width is that of its first
+/// codepoint (one column for an accented letter, two for a wide emoji),
+/// instead of summing the (possibly misleading) width of every codepoint
+/// inside the cluster.
+fn line_width(s: &str) -> usize {
+    s.graphemes(true)
+        .map(|grapheme| {
+            grapheme
+                .chars()
+                .next()
+                .and_then(UnicodeWidthChar::width)
+                .unwrap_or(0)
+        })
+        .sum()
 }
 
+/// Truncate `s` to fit within `max_width` columns, replacing the overhang
+/// with a trailing `…` (width 1). The ellipsis is counted against the
+/// budget itself, so for any `max_width` of at least 1 the returned string
+/// never exceeds `max_width`; with a `max_width` of 0 it is the ellipsis
+/// alone. If `s` already fits, it is returned unchanged.
+fn elide_to_width(s: &str, max_width: usize) -> String {
+    if UnicodeWidthStr::width(s) <= max_width {
+        return s.to_owned();
+    }
+
+    // Reserve one column for the ellipsis before accumulating.
+    let budget = max_width.saturating_sub(1);
+    let mut elided = String::new();
+    let mut width = 0;
+    for ch in s.chars() {
+        let ch_width = UnicodeWidthChar::width(ch).unwrap_or(0);
+        if width + ch_width > budget {
+            break;
+        }
+        width += ch_width;
+        elided.push(ch);
+    }
+    elided.push('…');
+    elided
+}
+
+static WHITESPACE_RE: OnceLock<Regex> = OnceLock::new();
+
 /// Merge continues white spaces into one space character while preserving newline characters.
 fn merge_white_spaces(input: &str) -> String {
-    let re = Regex::new(r"([^\S\r\n])+").unwrap();
+    let re = WHITESPACE_RE.get_or_init(|| Regex::new(r"([^\S\r\n])+").unwrap());
     re.replace_all(input, " ").to_string()
 }
+
+/// Split `input` on its existing line breaks and handle each line on its
+/// own: a line that already fits `max_width` is kept as-is, and a line that
+/// doesn't is wrapped with its leading indentation carried over as the
+/// continuation prefix. Unlike `merge_white_spaces` + `fill`, this never
+/// touches interior whitespace.
+fn format_verbatim(input: &str, max_width: usize) -> Vec<String> {
+    let mut lines = Vec::new();
+    for raw_line in input.split_inclusive('\n') {
+        let raw_line = raw_line.strip_suffix('\n').unwrap_or(raw_line);
+
+        if line_width(raw_line) <= max_width {
+            lines.push(raw_line.to_owned());
+            continue;
+        }
+
+        let indent: String = raw_line
+            .chars()
+            .take_while(|c| *c == ' ' || *c == '\t')
+            .collect();
+        let options = textwrap::Options::new(max_width).subsequent_indent(&indent);
+        lines.extend(fill(raw_line, options).lines().map(str::to_owned));
+    }
+    lines
+}